@@ -8,7 +8,60 @@ extern crate libc;
 #[cfg(target_os = "linux")]
 mod linux;
 #[cfg(target_os = "linux")]
-use linux::{get_num_cpus, get_num_physical_cpus};
+use linux::{
+    cpu_freq_mhz as cpu_freq_mhz_impl, get_configured_cpus, get_num_cpus, get_num_numa_nodes,
+    get_num_physical_cpus, get_num_sockets, get_online_cpus,
+};
+
+/// 描述一台机器的 CPU 拓扑结构：逻辑核心、物理核心、插槽（socket/package）以及 NUMA 节点，
+/// 近似对应 `lscpu` 输出中的 `CPU(s)`、`Core(s) per socket`、`Socket(s)`、`NUMA node(s)`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpuTopology {
+    /// 逻辑 CPU 数，等同于 [`get()`] 的结果。
+    pub logical_cpus: usize,
+    /// 物理核心数，等同于 [`get_physical()`] 的结果。
+    pub physical_cores: usize,
+    /// 物理插槽（socket/package）数。
+    pub sockets: usize,
+    /// NUMA 节点数。
+    pub numa_nodes: usize,
+}
+
+/// 返回当前机器的 CPU 拓扑结构，包含逻辑核心、物理核心、插槽以及 NUMA 节点数。
+/// 相比 [`get()`] 与 [`get_physical()`]，这个接口让调度器等场景可以感知 NUMA 局部性。
+/// # 用法
+/// ```rust
+/// let topology = chen_num_cpus::topology();
+/// println!("{} logical cpus across {} sockets", topology.logical_cpus, topology.sockets);
+/// ```
+pub fn topology() -> CpuTopology {
+    CpuTopology {
+        logical_cpus: get(),
+        physical_cores: get_physical(),
+        sockets: get_num_sockets_impl(),
+        numa_nodes: get_num_numa_nodes_impl(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_num_sockets_impl() -> usize {
+    get_num_sockets()
+}
+
+#[cfg(target_os = "linux")]
+fn get_num_numa_nodes_impl() -> usize {
+    get_num_numa_nodes()
+}
+
+#[cfg(windows)]
+fn get_num_sockets_impl() -> usize {
+    windows_topology_counts().0
+}
+
+#[cfg(windows)]
+fn get_num_numa_nodes_impl() -> usize {
+    windows_topology_counts().1
+}
 
 /// 返回当前系统的可用 CPU 数。此函数将获取逻辑内核数。
 /// 有时这与物理内核的数量不同（请参阅维基百科上的同步多线程）。这将始终返回至少 1。
@@ -90,28 +143,88 @@ pub fn get_physical() -> usize {
     get_num_physical_cpus()
 }
 
+/// 返回当前系统中在线（可调度）的 CPU 数量。
+/// 在 Linux 上它来自系统的在线 CPU 列表，不受进程亲和性掩码与 cgroup 配额的限制，
+/// 因此可能大于 [`get()`]；在 Windows 上与 [`get()`] 相同。
+/// 与 [`get_configured()`] 相对，对应 `lscpu` 的 ONLINE 一列。
+pub fn get_online() -> usize {
+    get_online_impl()
+}
+
+/// 返回系统中所有已配置（present）的 CPU 数量，包括出于省电等原因被下线（offline）的核心。
+/// 在存在动态下线核心的系统上，这个数字可能大于 [`get_online()`]，
+/// 对应 `lscpu` 的 CONFIGURED 一列。
+pub fn get_configured() -> usize {
+    get_configured_impl()
+}
+
+#[cfg(target_os = "linux")]
+fn get_online_impl() -> usize {
+    get_online_cpus()
+}
+
+#[cfg(target_os = "linux")]
+fn get_configured_impl() -> usize {
+    get_configured_cpus()
+}
+
+#[cfg(windows)]
+fn get_online_impl() -> usize {
+    get_num_cpus()
+}
+
+#[cfg(windows)]
+fn get_configured_impl() -> usize {
+    get_num_cpus()
+}
+
+/// 返回系统中 CPU 的最小与最大时钟频率（单位 MHz），对应 `lscpu` 的 `MINMHZ`/`MAXMHZ` 两列。
+/// 在异构核心（big.LITTLE）的机器上，不同核心的频率可能并不相同，调用方可以据此判断
+/// 是否需要按核心区别对待线程池大小，而不只是简单地看核心数量。
+/// 如果平台不提供这类信息（例如虚拟机里缺失 cpufreq sysfs 树），则返回 `None`。
+pub fn cpu_freq_mhz() -> Option<(f64, f64)> {
+    cpu_freq_mhz_impl()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn cpu_freq_mhz_impl() -> Option<(f64, f64)> {
+    None
+}
+
 #[cfg(target_os = "windows")]
 fn get_num_physical_cpus() -> usize {
     get_num_physical_cpus_windows().unwrap_or_else(|| get_num_cpus())
 }
 
-#[cfg(target_os = "windows")]
-fn get_num_physical_cpus_windows() -> Option<usize> {
-    use std::ptr;
-    use std::mem;
+/// `#[repr(C)]` 注解用于指定结构体在 C 语言中的布局方式。
+/// `#[allow(non_camel_case_types)]` 属性的作用是允许使用非驼峰式命名（non_camel_case）作为类型名。
+#[cfg(windows)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct SYSTEM_LOGICAL_PROCESSOR_INFORMATION {
+    mask: usize,
+    relationship: u32,
+    _unused: [u64; 2],
+}
 
-    #[allow(non_upper_case_globals)]
-    const RelationProcessorCore: u32 = 0;
+#[cfg(windows)]
+#[allow(non_upper_case_globals)]
+const RelationProcessorCore: u32 = 0;
+#[cfg(windows)]
+#[allow(non_upper_case_globals)]
+const RelationNumaNode: u32 = 1;
+#[cfg(windows)]
+#[allow(non_upper_case_globals)]
+const RelationProcessorPackage: u32 = 3;
 
-    /// `#[repr(C)]` 注解用于指定结构体在 C 语言中的布局方式。
-    /// `#[allow(non_camel_case_types)]` 属性的作用是允许使用非驼峰式命名（non_camel_case）作为类型名。
-    #[repr(C)]
-    #[allow(non_camel_case_types)]
-    struct SYSTEM_LOGICAL_PROCESSOR_INFORMATION {
-        mask: usize,
-        relationship: u32,
-        _unused: [u64; 2],
-    }
+/// 借助 `GetLogicalProcessorInformation` 取回一份处理器拓扑记录，每条记录描述
+/// 一个核心、插槽（socket/package）或 NUMA 节点等“关系”（relationship）。
+/// [`get_num_physical_cpus_windows`] 与 [`windows_topology_counts`] 共用这个函数，
+/// 各自按关心的 `relationship` 过滤统计；每次调用都会重新查询这个 Win32 API，结果不做缓存。
+#[cfg(windows)]
+fn fetch_logical_processor_information() -> Option<Vec<SYSTEM_LOGICAL_PROCESSOR_INFORMATION>> {
+    use std::ptr;
+    use std::mem;
 
     extern "system" {
         fn GetLogicalProcessorInformation(info: *mut SYSTEM_LOGICAL_PROCESSOR_INFORMATION, length: &mut u32) -> u32;
@@ -153,6 +266,13 @@ fn get_num_physical_cpus_windows() -> Option<usize> {
         buf.set_len(count as usize);
     }
 
+    Some(buf)
+}
+
+#[cfg(target_os = "windows")]
+fn get_num_physical_cpus_windows() -> Option<usize> {
+    let buf = fetch_logical_processor_information()?;
+
     let phys_proc_count: usize = buf.iter()
         .filter(|proc_info| proc_info.relationship == RelationProcessorCore)
         .count();
@@ -162,4 +282,111 @@ fn get_num_physical_cpus_windows() -> Option<usize> {
     } else {
         Some(phys_proc_count)
     }
+}
+
+/// 统计物理插槽（socket/package）数与 NUMA 节点数，分别对应 [`fetch_logical_processor_information`]
+/// 返回记录中的 `RelationProcessorPackage` 与 `RelationNumaNode`。
+#[cfg(windows)]
+fn windows_topology_counts() -> (usize, usize) {
+    let buf = match fetch_logical_processor_information() {
+        Some(buf) => buf,
+        None => return (1, 1),
+    };
+
+    let sockets = buf.iter().filter(|proc_info| proc_info.relationship == RelationProcessorPackage).count();
+    let numa_nodes = buf.iter().filter(|proc_info| proc_info.relationship == RelationNumaNode).count();
+
+    (
+        if sockets == 0 { 1 } else { sockets },
+        if numa_nodes == 0 { 1 } else { numa_nodes },
+    )
+}
+
+/// 描述 CPU 的处理器体系结构，对应 Windows `SYSTEM_INFO` 里的 `wProcessorArchitecture`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuArch {
+    /// x86（32 位）
+    X86,
+    /// x64（AMD 或 Intel）
+    Amd64,
+    /// ARM（32 位）
+    Arm,
+    /// ARM64
+    Arm64,
+    /// 基于 Intel Itanium
+    Ia64,
+    /// 未知的体系结构
+    Unknown,
+}
+
+/// 返回当前机器的处理器体系结构。
+/// 在 Windows 上使用 `GetNativeSystemInfo` 而不是 `GetSystemInfo`，
+/// 这样即便当前进程是运行在 WOW64 下的 32 位进程，也能得到宿主机真实的体系结构；
+/// 在其他平台上根据编译目标的 `target_arch` 推断。
+pub fn arch() -> CpuArch {
+    arch_impl()
+}
+
+#[cfg(windows)]
+fn arch_impl() -> CpuArch {
+    #[repr(C)]
+    #[allow(non_snake_case)]
+    struct SYSTEM_INFO {
+        wProcessorArchitecture: u16,
+        wReserved: u16,
+        dwPageSize: u32,
+        lpMinimumApplicationAddress: *mut u8,
+        lpMaximumApplicationAddress: *mut u8,
+        dwActiveProcessorMask: *mut u8,
+        dwNumberOfProcessors: u32,
+        dwProcessorType: u32,
+        dwAllocationGranularity: u32,
+        wProcessorLevel: u16,
+        wProcessorRevision: u16,
+    }
+
+    extern "system" {
+        fn GetNativeSystemInfo(lpSystemInfo: *mut SYSTEM_INFO);
+    }
+
+    #[allow(non_upper_case_globals)]
+    const PROCESSOR_ARCHITECTURE_INTEL: u16 = 0;
+    #[allow(non_upper_case_globals)]
+    const PROCESSOR_ARCHITECTURE_ARM: u16 = 5;
+    #[allow(non_upper_case_globals)]
+    const PROCESSOR_ARCHITECTURE_IA64: u16 = 6;
+    #[allow(non_upper_case_globals)]
+    const PROCESSOR_ARCHITECTURE_AMD64: u16 = 9;
+    #[allow(non_upper_case_globals)]
+    const PROCESSOR_ARCHITECTURE_ARM64: u16 = 12;
+
+    let mut system_info: SYSTEM_INFO = unsafe { std::mem::zeroed() };
+
+    unsafe {
+        GetNativeSystemInfo(&mut system_info);
+    }
+
+    match system_info.wProcessorArchitecture {
+        PROCESSOR_ARCHITECTURE_INTEL => CpuArch::X86,
+        PROCESSOR_ARCHITECTURE_AMD64 => CpuArch::Amd64,
+        PROCESSOR_ARCHITECTURE_ARM => CpuArch::Arm,
+        PROCESSOR_ARCHITECTURE_ARM64 => CpuArch::Arm64,
+        PROCESSOR_ARCHITECTURE_IA64 => CpuArch::Ia64,
+        _ => CpuArch::Unknown,
+    }
+}
+
+#[cfg(not(windows))]
+fn arch_impl() -> CpuArch {
+    if cfg!(target_arch = "x86") {
+        CpuArch::X86
+    } else if cfg!(target_arch = "x86_64") {
+        CpuArch::Amd64
+    } else if cfg!(target_arch = "arm") {
+        CpuArch::Arm
+    } else if cfg!(target_arch = "aarch64") {
+        CpuArch::Arm64
+    } else {
+        CpuArch::Unknown
+    }
 }
\ No newline at end of file
@@ -50,14 +50,6 @@ struct Subsys {
 
 impl Subsys {
     fn load_cpu<P>(proc_path: P) -> Option<Subsys> where P: AsRef<Path> {
-        let file = File::open(&proc_path).unwrap_or_else(|_| panic!("Failed to open /proc/self/cgroup"));
-        let buf_reader = BufReader::new(file);
-
-        // 逐行读取文件内容
-        for line in buf_reader.lines() {
-            println!("- {}", line.unwrap())
-        }
-
         let file = File::open(proc_path).unwrap_or_else(|_| panic!("Failed to open /proc/self/cgroup"));
         let buf_reader = BufReader::new(file);
 
@@ -99,11 +91,38 @@ impl Subsys {
 }
 
 pub fn get_num_cpus() -> usize {
-    cgroups_num_cpus().unwrap_or_else(|| logical_cpus())
+    let logical = logical_cpus();
+
+    match cgroups_num_cpus() {
+        // cgroups 配额永远不应该比亲和性掩码允许的核心数更多，取两者中较小的值。
+        Some(quota) if quota > 0 => std::cmp::min(logical, quota),
+        _ => logical,
+    }
 }
 
+/// 获取当前进程可用的逻辑 CPU 数量。
+///
+/// 通过 `sched_getaffinity` 读取进程的 CPU 亲和性掩码并统计其中被置位的 CPU，
+/// 这样在容器内或者被 `taskset` 限定了可用核心的场景下也能得到正确的结果。
+/// 如果该系统调用失败，则退回到 `sysconf(_SC_NPROCESSORS_ONLN)`，并保证至少返回 1。
 fn logical_cpus() -> usize {
-    0
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) == 0 {
+            let count = libc::CPU_COUNT(&set) as usize;
+            if count > 0 {
+                return count;
+            }
+        }
+    }
+
+    let cpus = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
+
+    if cpus < 1 {
+        1
+    } else {
+        cpus as usize
+    }
 }
 
 fn cgroups_num_cpus() -> Option<usize> {
@@ -111,8 +130,10 @@ fn cgroups_num_cpus() -> Option<usize> {
     static ONCE: Once = Once::new();
     ONCE.call_once(init_cgroups);
 
-
-    None
+    match CGROUPS_CPUS.load(Ordering::SeqCst) {
+        0 => None,
+        cpus => Some(cpus),
+    }
 }
 
 /// 获取 cgroups 中的 CPU 数
@@ -120,8 +141,8 @@ fn cgroups_num_cpus() -> Option<usize> {
 static CGROUPS_CPUS: AtomicUsize = AtomicUsize::new(0);
 
 fn init_cgroups() {
-    /// 仅在 debug 模式下执行，指定使用 `Ordering::SeqCst` 加载操作的内存顺序，
-    /// 这确保了在加载 `CGROUPS_CPUS` 变量的值时使用顺序一致性顺序。
+    // 仅在 debug 模式下执行，指定使用 `Ordering::SeqCst` 加载操作的内存顺序，
+    // 这确保了在加载 `CGROUPS_CPUS` 变量的值时使用顺序一致性顺序。
     debug_assert!(CGROUPS_CPUS.load(Ordering::SeqCst) == 0);
 
     // 检查当前是否是使用 miri 工具进行编译和执行的，如果是，返回true。
@@ -129,28 +150,537 @@ fn init_cgroups() {
         return;
     }
 
-    /// 加载 cgroups
-    /// 1. /proc/self/cgroup：
-    ///
-    ///      `/proc/self/cgroup` 是一个用于查看当前进程所属的 cgroups（控制组）信息的虚拟文件。/proc/self 是一个符号链接，指向当前进程的虚拟文件系统路径。
-    ///     这个文件通常用于查看当前进程所属的 cgroups 层次结构，以及各个 cgroup 的名称和配置信息。
-    ///     每一行代表一个 cgroup，并列出了当前进程在该 cgroup 中的控制信息，如 cgroup 的名称、层次结构路径等。
-    /// 2. /proc/self/mountinfo：
-    ///
-    ///     `/proc/self/mountinfo` 是一个用于查看当前进程的挂载信息的虚拟文件。
-    ///     这个文件提供了有关当前进程所在的挂载点、文件系统类型、挂载选项等详细信息。
-    ///     每一行表示一个挂载点的信息，包括挂载的源路径、目标路径、文件系统类型、挂载选项等。
-    if let Some(quota) = load_cgroups("/proc/self/cgroup", "/proc/self/mountinfo") {}
+    // 加载 cgroups
+    // 1. /proc/self/cgroup：
+    //
+    //      `/proc/self/cgroup` 是一个用于查看当前进程所属的 cgroups（控制组）信息的虚拟文件。/proc/self 是一个符号链接，指向当前进程的虚拟文件系统路径。
+    //     这个文件通常用于查看当前进程所属的 cgroups 层次结构，以及各个 cgroup 的名称和配置信息。
+    //     每一行代表一个 cgroup，并列出了当前进程在该 cgroup 中的控制信息，如 cgroup 的名称、层次结构路径等。
+    // 2. /proc/self/mountinfo：
+    //
+    //     `/proc/self/mountinfo` 是一个用于查看当前进程的挂载信息的虚拟文件。
+    //     这个文件提供了有关当前进程所在的挂载点、文件系统类型、挂载选项等详细信息。
+    //     每一行表示一个挂载点的信息，包括挂载的源路径、目标路径、文件系统类型、挂载选项等。
+    if let Some(quota) = load_cgroups("/proc/self/cgroup", "/proc/self/mountinfo") {
+        CGROUPS_CPUS.store(quota, Ordering::SeqCst);
+    }
 }
 
 fn load_cgroups<P1, P2>(cgroup_proc: P1, mountinfo_proc: P2) -> Option<usize>
-    where P1: AsRef<std::path::Path>, P2: AsRef<std::path::Path> {
+    where P1: AsRef<Path>, P2: AsRef<Path> {
     let subsys = some!(Subsys::load_cpu(cgroup_proc));
-    println!("subsys: {:?}", subsys);
+    let mount_path = some!(find_cgroup_mount(&subsys, mountinfo_proc));
+
+    match subsys.version {
+        CgroupVersion::V1 => quota_v1(&mount_path),
+        CgroupVersion::V2 => quota_v2(&mount_path),
+    }
+}
 
-    return None;
+/// 在 `/proc/self/mountinfo` 中找到 `subsys` 对应的挂载点，并与 `/proc/self/cgroup`
+/// 中记录的相对路径拼接，得到该 cgroup 在宿主文件系统上的真实路径。
+///
+/// mountinfo 每一行的格式大致为：
+/// `36 35 98:0 /subdir /mnt/point rw,noatime master:1 - cgroup cgroup rw,cpu,cpuacct`
+/// 分隔符 `-` 之前是挂载点等公共字段，之后是文件系统类型、挂载源以及 super options。
+fn find_cgroup_mount<P>(subsys: &Subsys, mountinfo_proc: P) -> Option<String> where P: AsRef<Path> {
+    let file = some!(File::open(mountinfo_proc).ok());
+    let buf_reader = BufReader::new(file);
+
+    buf_reader.lines()
+        .map_while(Result::ok)
+        .find_map(|line| parse_mountinfo_line(&line, subsys))
 }
 
+fn parse_mountinfo_line(line: &str, subsys: &Subsys) -> Option<String> {
+    let mut halves = line.splitn(2, " - ");
+    let left = some!(halves.next());
+    let right = some!(halves.next());
+
+    // 左半部分的字段依次为：挂载 ID、父挂载 ID、major:minor、root、挂载点、挂载选项...
+    let left_fields: Vec<&str> = left.split_whitespace().collect();
+    let root = *some!(left_fields.get(3));
+    let mount_point = *some!(left_fields.get(4));
+
+    // 右半部分的字段依次为：文件系统类型、挂载源、super options。
+    let mut right_fields = right.split_whitespace();
+    let fs_type = some!(right_fields.next());
+
+    let wanted_fs_type = match subsys.version {
+        CgroupVersion::V1 => "cgroup",
+        CgroupVersion::V2 => "cgroup2",
+    };
+
+    if fs_type != wanted_fs_type {
+        return None;
+    }
+
+    if subsys.version == CgroupVersion::V1 {
+        let super_options = some!(right_fields.nth(1));
+        if !super_options.split(',').any(|opt| opt == "cpu") {
+            return None;
+        }
+    }
+
+    // `root` 是这个挂载点在其自身文件系统里对应的根目录。在容器里，cgroup 的挂载点
+    // 本身往往就是容器自己的那个 slice（root != "/"），这时 `/proc/self/cgroup` 里的
+    // `subsys.base` 是相对于宿主机 cgroup 树根的路径，需要先去掉 `root` 这段前缀，
+    // 剩下的部分才是相对于这个挂载点的相对路径。当 root 是 "/" 时行为不变。
+    let relative_base = if root == "/" {
+        subsys.base.as_str()
+    } else {
+        subsys.base.strip_prefix(root).unwrap_or(subsys.base.as_str())
+    };
+
+    Some(format!("{}{}", mount_point, relative_base))
+}
+
+/// cgroup v1：读取 `cpu.cfs_quota_us` 与 `cpu.cfs_period_us`。
+/// 配额为负数表示未设置限制，此时返回 `None`。
+fn quota_v1(base: &str) -> Option<usize> {
+    let quota: i64 = some!(read_file(&format!("{}/cpu.cfs_quota_us", base))
+        .and_then(|s| s.trim().parse().ok()));
+
+    if quota <= 0 {
+        return None;
+    }
+
+    let period: u64 = some!(read_file(&format!("{}/cpu.cfs_period_us", base))
+        .and_then(|s| s.trim().parse().ok()));
+
+    if period == 0 {
+        return None;
+    }
+
+    Some((quota as f64 / period as f64).ceil() as usize)
+}
+
+/// cgroup v2：`cpu.max` 文件内容形如 `"<quota> <period>"`，quota 为 `max` 时表示未设置限制。
+fn quota_v2(base: &str) -> Option<usize> {
+    let content = some!(read_file(&format!("{}/cpu.max", base)));
+    let mut fields = content.split_whitespace();
+
+    let quota_field = some!(fields.next());
+    if quota_field == "max" {
+        return None;
+    }
+
+    let quota: f64 = some!(quota_field.parse().ok());
+    let period: f64 = some!(fields.next().and_then(|p| p.parse().ok()));
+
+    if period <= 0.0 {
+        return None;
+    }
+
+    Some((quota / period).ceil() as usize)
+}
+
+fn read_file(path: &str) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+}
+
+/// 对应 `lscpu` 的 `ON-LINE CPU(S) LIST`：当前在线（可调度）的 CPU 数量，
+/// 通过展开 `/sys/devices/system/cpu/online` 中的区间列表（如 `"0-3,6"`）得到。
+/// 如果该文件不存在或内容无法解析，则退回到亲和性限定的逻辑核心数。
+pub fn get_online_cpus() -> usize {
+    parse_range_list_file("/sys/devices/system/cpu/online").unwrap_or_else(get_num_cpus)
+}
+
+/// 对应 `lscpu` 的 `CONFIGURED CPU(S) LIST`：系统中所有存在的 CPU 数量，
+/// 包括被下线（offline）的核心，来自 `/sys/devices/system/cpu/present`。
+pub fn get_configured_cpus() -> usize {
+    parse_range_list_file("/sys/devices/system/cpu/present").unwrap_or_else(get_num_cpus)
+}
+
+fn parse_range_list_file<P>(path: P) -> Option<usize> where P: AsRef<Path> {
+    let content = std::fs::read_to_string(path).ok()?;
+    parse_range_list(content.trim())
+}
+
+/// 展开 Linux CPU 区间列表格式，例如 `"0-3,6"` 展开为 5 个 CPU（0、1、2、3、6）。
+fn parse_range_list(list: &str) -> Option<usize> {
+    if list.is_empty() {
+        return None;
+    }
+
+    let mut count = 0usize;
+
+    for part in list.split(',') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+
+        match part.split_once('-') {
+            Some((lo, hi)) => {
+                let lo: usize = lo.parse().ok()?;
+                let hi: usize = hi.parse().ok()?;
+                if hi < lo {
+                    return None;
+                }
+                count += hi - lo + 1;
+            }
+            None => {
+                part.parse::<usize>().ok()?;
+                count += 1;
+            }
+        }
+    }
+
+    Some(count)
+}
+
+/// 通过解析 `/proc/cpuinfo` 统计物理核心数。
+///
+/// `/proc/cpuinfo` 中每个逻辑处理器都是一个单独的块，块内的 `physical id` 标识所在的
+/// 物理插槽（socket），`core id` 标识插槽内的核心编号；同一块物理核心上的多个超线程
+/// 会共享相同的 `(physical id, core id)` 组合，因此将这个二元组放入集合去重后，
+/// 集合的大小就是物理核心数。如果缺少这两个字段（常见于部分 ARM 平台），则退回到
+/// 逻辑核心数。
 pub fn get_num_physical_cpus() -> usize {
-    0
+    match scan_cpuinfo("/proc/cpuinfo") {
+        Some(stats) if !stats.cores.is_empty() => stats.cores.len(),
+        _ => get_num_cpus(),
+    }
+}
+
+/// `/proc/cpuinfo` 中每个逻辑处理器对应一个单独的块，块内的 `physical id` 标识所在的
+/// 物理插槽（socket），`core id` 标识插槽内的核心编号。
+struct CpuInfoStats {
+    /// 同一块物理核心上的多个超线程共享相同的 `(physical id, core id)` 组合，
+    /// 去重后的集合大小即为物理核心数。
+    cores: std::collections::HashSet<(u32, u32)>,
+    /// 去重后的 `physical id` 集合大小即为物理插槽（socket）数。
+    physical_ids: std::collections::HashSet<u32>,
+}
+
+fn scan_cpuinfo<P>(cpuinfo_proc: P) -> Option<CpuInfoStats> where P: AsRef<Path> {
+    let file = File::open(cpuinfo_proc).ok()?;
+    let buf_reader = BufReader::new(file);
+
+    let mut physical_id: Option<u32> = None;
+    let mut core_id: Option<u32> = None;
+    let mut stats = CpuInfoStats {
+        cores: std::collections::HashSet::new(),
+        physical_ids: std::collections::HashSet::new(),
+    };
+
+    let flush = |physical_id: &mut Option<u32>, core_id: &mut Option<u32>, stats: &mut CpuInfoStats| {
+        if let Some(p) = *physical_id {
+            stats.physical_ids.insert(p);
+            if let Some(c) = *core_id {
+                stats.cores.insert((p, c));
+            }
+        }
+        *physical_id = None;
+        *core_id = None;
+    };
+
+    for line in buf_reader.lines().map_while(Result::ok) {
+        if line.is_empty() {
+            flush(&mut physical_id, &mut core_id, &mut stats);
+            continue;
+        }
+
+        let mut fields = line.splitn(2, ':');
+        let key = fields.next().unwrap_or("").trim();
+        let value = fields.next().map(|v| v.trim());
+
+        match (key, value) {
+            ("physical id", Some(v)) => physical_id = v.parse().ok(),
+            ("core id", Some(v)) => core_id = v.parse().ok(),
+            _ => {}
+        }
+    }
+
+    // 文件末尾可能没有空行作为最后一个处理器块的结尾，这里补上最后一次记录。
+    flush(&mut physical_id, &mut core_id, &mut stats);
+
+    Some(stats)
+}
+
+/// 统计物理插槽（socket）数量，对应 `lscpu` 中的 `Socket(s)`。如果 `/proc/cpuinfo`
+/// 里没有 `physical id` 字段，则认为只有一个插槽。
+pub fn get_num_sockets() -> usize {
+    match scan_cpuinfo("/proc/cpuinfo") {
+        Some(stats) if !stats.physical_ids.is_empty() => stats.physical_ids.len(),
+        _ => 1,
+    }
+}
+
+/// 统计 NUMA 节点数量，对应 `lscpu` 中的 `NUMA node(s)`：
+/// 每个 `/sys/devices/system/node/node<N>` 目录即为一个 NUMA 节点。
+/// 如果该目录不存在（例如在一些虚拟机或未启用 NUMA 的系统上），则认为只有一个节点。
+pub fn get_num_numa_nodes() -> usize {
+    count_numa_nodes("/sys/devices/system/node").unwrap_or(1)
+}
+
+fn count_numa_nodes<P>(node_dir: P) -> Option<usize> where P: AsRef<Path> {
+    let entries = std::fs::read_dir(node_dir).ok()?;
+
+    let count = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("node") && name[4..].parse::<u32>().is_ok()
+        })
+        .count();
+
+    if count == 0 {
+        None
+    } else {
+        Some(count)
+    }
+}
+
+/// 对应 `lscpu` 的 `MAXMHZ`/`MINMHZ` 两列：遍历所有 `/sys/devices/system/cpu/cpu<N>/cpufreq/`
+/// 目录，读取 `cpuinfo_max_freq` 与 `cpuinfo_min_freq`（单位为 kHz），取其中的最大值与最小值
+/// 并换算为 MHz。如果 cpufreq 这套 sysfs 树不存在（例如虚拟机里没有暴露频率信息），
+/// 则返回 `None`。
+pub fn cpu_freq_mhz() -> Option<(f64, f64)> {
+    scan_cpu_freq_mhz("/sys/devices/system/cpu")
+}
+
+fn scan_cpu_freq_mhz<P>(cpu_dir: P) -> Option<(f64, f64)> where P: AsRef<Path> {
+    let entries = std::fs::read_dir(cpu_dir).ok()?;
+
+    let mut min_khz: Option<u64> = None;
+    let mut max_khz: Option<u64> = None;
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+
+        if !name.starts_with("cpu") || name[3..].parse::<u32>().is_err() {
+            continue;
+        }
+
+        let cpufreq_dir = entry.path().join("cpufreq");
+
+        if let Some(max) = read_khz(&cpufreq_dir.join("cpuinfo_max_freq")) {
+            max_khz = Some(max_khz.map_or(max, |current| current.max(max)));
+        }
+
+        if let Some(min) = read_khz(&cpufreq_dir.join("cpuinfo_min_freq")) {
+            min_khz = Some(min_khz.map_or(min, |current| current.min(min)));
+        }
+    }
+
+    match (min_khz, max_khz) {
+        (Some(min), Some(max)) => Some((min as f64 / 1000.0, max as f64 / 1000.0)),
+        _ => None,
+    }
+}
+
+fn read_khz(path: &Path) -> Option<u64> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subsys(version: CgroupVersion, base: &str) -> Subsys {
+        Subsys { version: version, base: base.to_owned() }
+    }
+
+    #[test]
+    fn parse_mountinfo_line_joins_root_mount() {
+        let line = "24 23 0:9 / /sys/fs/cgroup/cpu,cpuacct rw,relatime shared:5 - cgroup cgroup rw,cpu,cpuacct";
+        let s = subsys(CgroupVersion::V1, "/docker/abc");
+
+        assert_eq!(parse_mountinfo_line(line, &s), Some("/sys/fs/cgroup/cpu,cpuacct/docker/abc".to_owned()));
+    }
+
+    #[test]
+    fn parse_mountinfo_line_strips_non_root_prefix() {
+        // Docker/Kubernetes commonly bind-mount the container's own cgroup slice as the
+        // visible root, so `root` (4th field) isn't "/" and must be stripped from `base`
+        // before it is appended to the mount point.
+        let line = "24 23 0:9 /large-open-mad-print /sys/fs/cgroup/cpu rw - cgroup none rw,cpu";
+        let s = subsys(CgroupVersion::V1, "/large-open-mad-print");
+
+        assert_eq!(parse_mountinfo_line(line, &s), Some("/sys/fs/cgroup/cpu".to_owned()));
+    }
+
+    #[test]
+    fn parse_mountinfo_line_strips_non_root_prefix_with_suffix() {
+        let line = "24 23 0:9 /large-open-mad-print /sys/fs/cgroup/cpu rw - cgroup none rw,cpu";
+        let s = subsys(CgroupVersion::V1, "/large-open-mad-print/child");
+
+        assert_eq!(parse_mountinfo_line(line, &s), Some("/sys/fs/cgroup/cpu/child".to_owned()));
+    }
+
+    #[test]
+    fn parse_mountinfo_line_rejects_wrong_fstype() {
+        let line = "24 23 0:9 / /sys/fs/cgroup/cpu rw - tmpfs tmpfs rw";
+        let s = subsys(CgroupVersion::V1, "/");
+
+        assert_eq!(parse_mountinfo_line(line, &s), None);
+    }
+
+    #[test]
+    fn parse_mountinfo_line_rejects_v1_without_cpu_controller() {
+        let line = "24 23 0:9 / /sys/fs/cgroup/memory rw - cgroup none rw,memory";
+        let s = subsys(CgroupVersion::V1, "/");
+
+        assert_eq!(parse_mountinfo_line(line, &s), None);
+    }
+
+    struct TempDir {
+        path: std::path::PathBuf,
+    }
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let path = std::env::temp_dir().join(format!("chen_num_cpus_test_{}_{}_{}", name, std::process::id(), name.len()));
+            std::fs::create_dir_all(&path).unwrap();
+            TempDir { path: path }
+        }
+
+        fn write(&self, file: &str, contents: &str) {
+            std::fs::write(self.path.join(file), contents).unwrap();
+        }
+
+        fn write_nested(&self, relative_path: &str, contents: &str) {
+            let full_path = self.path.join(relative_path);
+            std::fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+            std::fs::write(full_path, contents).unwrap();
+        }
+
+        fn mkdir(&self, relative_path: &str) {
+            std::fs::create_dir_all(self.path.join(relative_path)).unwrap();
+        }
+
+        fn path_str(&self) -> &str {
+            self.path.to_str().unwrap()
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn quota_v1_computes_ceiling_of_quota_over_period() {
+        let dir = TempDir::new("quota_v1_ok");
+        dir.write("cpu.cfs_quota_us", "50000\n");
+        dir.write("cpu.cfs_period_us", "100000\n");
+
+        assert_eq!(quota_v1(dir.path_str()), Some(1));
+    }
+
+    #[test]
+    fn quota_v1_unlimited_is_none() {
+        let dir = TempDir::new("quota_v1_unlimited");
+        dir.write("cpu.cfs_quota_us", "-1\n");
+        dir.write("cpu.cfs_period_us", "100000\n");
+
+        assert_eq!(quota_v1(dir.path_str()), None);
+    }
+
+    #[test]
+    fn quota_v2_computes_ceiling_of_quota_over_period() {
+        let dir = TempDir::new("quota_v2_ok");
+        dir.write("cpu.max", "250000 100000\n");
+
+        assert_eq!(quota_v2(dir.path_str()), Some(3));
+    }
+
+    #[test]
+    fn quota_v2_max_is_unlimited() {
+        let dir = TempDir::new("quota_v2_unlimited");
+        dir.write("cpu.max", "max 100000\n");
+
+        assert_eq!(quota_v2(dir.path_str()), None);
+    }
+
+    #[test]
+    fn scan_cpuinfo_groups_by_physical_and_core_id() {
+        let dir = TempDir::new("cpuinfo_groups");
+        // 最后一个处理器块故意不以空行结尾，用来覆盖文件末尾补记录的 flush 逻辑。
+        dir.write("cpuinfo", concat!(
+            "processor\t: 0\n",
+            "physical id\t: 0\n",
+            "core id\t: 0\n",
+            "\n",
+            "processor\t: 1\n",
+            "physical id\t: 0\n",
+            "core id\t: 1\n",
+            "\n",
+            "processor\t: 2\n",
+            "physical id\t: 1\n",
+            "core id\t: 0\n",
+            "\n",
+            "processor\t: 3\n",
+            "physical id\t: 1\n",
+            "core id\t: 0",
+        ));
+
+        let stats = scan_cpuinfo(dir.path.join("cpuinfo")).unwrap();
+
+        // cpu2 与 cpu3 是同一物理核心上的两个超线程，只计一次。
+        assert_eq!(stats.cores.len(), 3);
+        assert_eq!(stats.physical_ids.len(), 2);
+    }
+
+    #[test]
+    fn scan_cpuinfo_without_physical_id_yields_empty_sets() {
+        let dir = TempDir::new("cpuinfo_arm");
+        dir.write("cpuinfo", "processor\t: 0\n\nprocessor\t: 1\n");
+
+        let stats = scan_cpuinfo(dir.path.join("cpuinfo")).unwrap();
+
+        assert!(stats.cores.is_empty());
+        assert!(stats.physical_ids.is_empty());
+    }
+
+    #[test]
+    fn count_numa_nodes_counts_node_directories_only() {
+        let dir = TempDir::new("numa_nodes");
+        dir.mkdir("node0");
+        dir.mkdir("node1");
+        dir.mkdir("cpu0");
+
+        assert_eq!(count_numa_nodes(dir.path_str()), Some(2));
+    }
+
+    #[test]
+    fn count_numa_nodes_missing_directory_is_none() {
+        let dir = TempDir::new("numa_nodes_missing");
+
+        assert_eq!(count_numa_nodes(dir.path.join("does-not-exist")), None);
+    }
+
+    #[test]
+    fn cpu_freq_mhz_takes_max_of_maxes_and_min_of_mins() {
+        let dir = TempDir::new("cpu_freq");
+        dir.write_nested("cpu0/cpufreq/cpuinfo_max_freq", "2000000\n");
+        dir.write_nested("cpu0/cpufreq/cpuinfo_min_freq", "800000\n");
+        dir.write_nested("cpu1/cpufreq/cpuinfo_max_freq", "3000000\n");
+        dir.write_nested("cpu1/cpufreq/cpuinfo_min_freq", "400000\n");
+
+        assert_eq!(scan_cpu_freq_mhz(dir.path_str()), Some((400.0, 3000.0)));
+    }
+
+    #[test]
+    fn cpu_freq_mhz_missing_cpufreq_tree_is_none() {
+        let dir = TempDir::new("cpu_freq_missing");
+        dir.mkdir("cpu0");
+
+        assert_eq!(scan_cpu_freq_mhz(dir.path_str()), None);
+    }
+
+    #[test]
+    fn parse_range_list_expands_ranges_and_singletons() {
+        assert_eq!(parse_range_list("0-3,6"), Some(5));
+    }
+
+    #[test]
+    fn parse_range_list_rejects_empty_input() {
+        assert_eq!(parse_range_list(""), None);
+    }
 }
\ No newline at end of file